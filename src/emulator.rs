@@ -0,0 +1,14 @@
+use crate::Address;
+
+/// Translates an address in the memory of the emulated system into an address
+/// in the memory of the emulator, given where the emulated RAM starts.
+pub const fn translate(ram_base: Address, guest_addr: u32) -> Address {
+    Address(ram_base.0.wrapping_add(guest_addr as u64))
+}
+
+/// Like [`translate`], but the guest address is masked first. This handles
+/// systems that mirror their RAM across the address space, where for example a
+/// mask of `0x1FFFFF` maps every mirror of 2 MiB of RAM onto the same memory.
+pub const fn translate_masked(ram_base: Address, guest_addr: u32, mask: u32) -> Address {
+    translate(ram_base, guest_addr & mask)
+}
@@ -0,0 +1,38 @@
+use crate::{Address, Error, Process};
+use bytemuck::Pod;
+
+/// A value that gets written to the same address every tick, keeping the game
+/// from changing it, until it gets released.
+#[derive(Debug, Copy, Clone)]
+pub struct FrozenValue<T> {
+    address: Address,
+    value: T,
+    released: bool,
+}
+
+impl<T: Pod> FrozenValue<T> {
+    pub const fn new(address: Address, value: T) -> Self {
+        Self {
+            address,
+            value,
+            released: false,
+        }
+    }
+
+    /// Writes the value again. This should be called every tick. Once the
+    /// value got released, this does nothing.
+    pub fn tick(&self, process: &Process) -> Result<(), Error> {
+        if self.released {
+            return Ok(());
+        }
+        process.write(self.address, &self.value)
+    }
+
+    pub fn release(&mut self) {
+        self.released = true;
+    }
+
+    pub fn is_released(&self) -> bool {
+        self.released
+    }
+}
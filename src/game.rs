@@ -0,0 +1,36 @@
+use crate::{yield_ticks, Process};
+
+/// Structures an auto splitter around the lifecycle of the game's process.
+/// [`run_game`] takes care of attaching, noticing when the process closes and
+/// attaching again once the game is restarted.
+pub trait Game {
+    /// The names of the processes to attach to. The first one found is used.
+    const PROCESS_NAMES: &'static [&'static str];
+
+    /// Called once every time the process got attached, before the first
+    /// [`update`](Self::update). This is where offsets should be looked up and
+    /// state of a previous process discarded.
+    fn attached(&mut self, _process: &Process) {}
+
+    /// Called every tick while the process is open.
+    fn update(&mut self, process: &Process);
+}
+
+/// Drives a [`Game`] forever. Every tick, this either tries to attach to one of
+/// the game's processes or updates the game with the process attached. Once the
+/// process closes, it gets detached and attaching starts over.
+pub fn run_game<G: Game>(game: &mut G) -> ! {
+    loop {
+        let process = loop {
+            if let Some(process) = Process::attach_any(G::PROCESS_NAMES) {
+                break process;
+            }
+            yield_ticks(1);
+        };
+        game.attached(&process);
+        while process.is_open() {
+            game.update(&process);
+            yield_ticks(1);
+        }
+    }
+}
@@ -1,6 +1,17 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod emulator;
+pub mod frozen_value;
+pub mod game;
+pub mod pointer_path;
 mod runtime;
+#[cfg(feature = "alloc")]
+pub mod signature;
+#[cfg(feature = "alloc")]
+pub mod unity;
 pub mod watcher;
 
 pub use self::runtime::*;
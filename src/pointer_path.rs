@@ -0,0 +1,105 @@
+use crate::{Address, Error, PointerWidth, Process};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use bytemuck::Pod;
+
+/// A pointer path relative to a module that caches the address of its last
+/// link. As long as reading from the cached link succeeds, the module base and
+/// the earlier links are not resolved again. This means that a path whose
+/// earlier links change while the last link stays readable keeps reading from
+/// the old location until the cache gets reset.
+#[derive(Debug, Clone)]
+pub struct PointerPath<'a> {
+    module: &'a str,
+    path: &'a [u64],
+    cache: Option<Address>,
+}
+
+impl<'a> PointerPath<'a> {
+    pub const fn new(module: &'a str, path: &'a [u64]) -> Self {
+        Self {
+            module,
+            path,
+            cache: None,
+        }
+    }
+
+    pub fn read<T: Pod>(&mut self, process: &Process) -> Result<T, Error> {
+        let (&last, path) = self.path.split_last().ok_or(Error::EmptyPath)?;
+
+        if let Some(address) = self.cache {
+            if let Ok(value) = process.read(Address(address.0.wrapping_add(last))) {
+                return Ok(value);
+            }
+            self.cache = None;
+        }
+
+        let mut address = process.get_module(self.module)?.0;
+        for &offset in path {
+            address = process.read(Address(address.wrapping_add(offset)))?;
+            if address == 0 {
+                return Err(Error::NullPointer);
+            }
+        }
+        let value = process.read(Address(address.wrapping_add(last)))?;
+        self.cache = Some(Address(address));
+        Ok(value)
+    }
+
+    pub fn reset_cache(&mut self) {
+        self.cache = None;
+    }
+}
+
+/// A base address and the offsets to follow from it, like the `DeepPointer` of
+/// LiveSplit's scripts. Unlike [`PointerPath`], nothing is cached, so the path
+/// gets walked again on every dereference. The pointers along the path are
+/// read with the width given, so the same type works for 32-bit and 64-bit
+/// games.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DeepPointer<'a> {
+    base: Address,
+    path: &'a [u64],
+    width: PointerWidth,
+}
+
+impl<'a> DeepPointer<'a> {
+    pub const fn new(base: Address, path: &'a [u64], width: PointerWidth) -> Self {
+        Self { base, path, width }
+    }
+
+    /// Reads the value the pointer points to.
+    pub fn deref<T: Pod>(&self, process: &Process) -> Result<T, Error> {
+        process.read(self.deref_offsets(process)?)
+    }
+
+    /// Resolves the address the pointer points to without reading the value.
+    pub fn deref_offsets(&self, process: &Process) -> Result<Address, Error> {
+        process.resolve_pointer_path(self.base, self.width, self.path)
+    }
+}
+
+/// An offset relative to the base of a module. Unlike an absolute address,
+/// this stays valid when the module gets loaded at a different address, such
+/// as when the game is launched again.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ModuleOffset {
+    pub module: String,
+    pub offset: u64,
+}
+
+#[cfg(feature = "alloc")]
+impl ModuleOffset {
+    pub fn new(module: impl Into<String>, offset: u64) -> Self {
+        Self {
+            module: module.into(),
+            offset,
+        }
+    }
+
+    /// Adds the offset to the current base of the module.
+    pub fn resolve(&self, process: &Process) -> Result<Address, Error> {
+        Ok(process.get_module(&self.module)? + self.offset)
+    }
+}
@@ -1,17 +1,32 @@
-use bytemuck::Pod;
+#[cfg(feature = "alloc")]
+use crate::signature::Signature;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "alloc")]
+use core::iter;
 use core::{
+    fmt,
     mem::{self, MaybeUninit},
-    ops::Add,
-    slice,
+    num::NonZeroU64,
+    ops::{Add, AddAssign, Sub},
+    slice, str,
+    sync::atomic::{self, AtomicBool, AtomicU64},
 };
 
-pub use self::sys::Address;
 use self::sys::ProcessId;
+pub use self::sys::{Address, NonZeroAddress};
 
+// Some of the functions are only used by the APIs that allocate.
+#[cfg_attr(not(feature = "alloc"), allow(dead_code))]
 mod sys {
     use core::num::NonZeroU64;
 
-    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    /// An address in the memory of a process. The arithmetic operators always
+    /// wrap around at the boundaries of the address space, in both debug and
+    /// release builds. Use [`checked_add`](Self::checked_add) to detect
+    /// overflows instead.
+    #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
     #[repr(transparent)]
     pub struct Address(pub u64);
 
@@ -21,7 +36,7 @@ mod sys {
 
     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
     #[repr(transparent)]
-    pub struct ProcessId(NonZeroU64);
+    pub struct ProcessId(pub NonZeroU64);
 
     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
     #[repr(transparent)]
@@ -39,9 +54,46 @@ mod sys {
         pub const ENDED: Self = Self(3);
     }
 
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct MemoryRangeFlags(pub u64);
+
+    impl MemoryRangeFlags {
+        /// The memory range is readable.
+        pub const READ: u64 = 1 << 1;
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[repr(C)]
+    pub struct MemoryRange {
+        pub address: Address,
+        pub size: u64,
+        pub flags: MemoryRangeFlags,
+    }
+
+    /// The maximum length in bytes of a module name in a [`ModuleRecord`].
+    /// Longer names are truncated.
+    pub const MODULE_NAME_LEN: usize = 256;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[repr(C)]
+    pub struct ModuleRecord {
+        pub base: Address,
+        pub size: u64,
+        /// The amount of bytes of `name` that are used.
+        pub name_len: u64,
+        pub name: [u8; MODULE_NAME_LEN],
+    }
+
     extern "C" {
         /// Gets the state that the timer currently is in.
         pub fn timer_get_state() -> TimerState;
+        /// Gets the amount of attempts that have been started, including the
+        /// current one.
+        pub fn timer_get_attempt_count() -> u32;
+        /// Gets the index of the split the timer is currently on. Returns
+        /// `-1` if the timer is not running.
+        pub fn timer_get_current_split_index() -> i64;
 
         /// Starts the timer.
         pub fn timer_start();
@@ -49,6 +101,16 @@ mod sys {
         pub fn timer_split();
         /// Resets the timer.
         pub fn timer_reset();
+        /// Skips the current split. Does nothing if the timer isn't running.
+        pub fn timer_skip_split();
+        /// Undoes the previous split. Does nothing if the timer isn't running.
+        pub fn timer_undo_split();
+        /// Pauses the timer. This is separate from pausing the game time, which
+        /// only stops the automatic flow of time for the game time.
+        pub fn timer_pause();
+        /// Resumes the timer after it got paused. This is separate from
+        /// resuming the game time.
+        pub fn timer_resume();
         /// Sets a custom key value pair. This may be arbitrary information that
         /// the auto splitter wants to provide for visualization.
         pub fn timer_set_variable(
@@ -60,6 +122,9 @@ mod sys {
 
         /// Sets the game time.
         pub fn timer_set_game_time(secs: i64, nanos: i32);
+        /// Gets the current game time. Returns `false` if the timer is not
+        /// running.
+        pub fn timer_get_game_time(secs_ptr: *mut i64, nanos_ptr: *mut i32) -> bool;
         /// Pauses the game time. This does not pause the timer, only the
         /// automatic flow of time for the game time.
         pub fn timer_pause_game_time();
@@ -69,6 +134,8 @@ mod sys {
 
         /// Attaches to a process based on its name.
         pub fn process_attach(name_ptr: *const u8, name_len: usize) -> Option<ProcessId>;
+        /// Attaches to a process based on its process id.
+        pub fn process_attach_by_pid(pid: u64) -> Option<ProcessId>;
         /// Detaches from a process.
         pub fn process_detach(process: ProcessId);
         /// Checks whether is a process is still open. You should detach from a
@@ -82,31 +149,251 @@ mod sys {
             buf_ptr: *mut u8,
             buf_len: usize,
         ) -> bool;
+        /// Reads as much memory as possible from a process at the address
+        /// given into the buffer given. Returns the amount of bytes read from
+        /// the start of the buffer.
+        pub fn process_read_partial(
+            process: ProcessId,
+            address: Address,
+            buf_ptr: *mut u8,
+            buf_len: usize,
+        ) -> usize;
+        /// Writes memory to a process at the address given. This will write
+        /// the whole buffer given to the memory. Returns `false` if this fails,
+        /// including when only part of the buffer could be written.
+        pub fn process_write(
+            process: ProcessId,
+            address: Address,
+            buf_ptr: *const u8,
+            buf_len: usize,
+        ) -> bool;
+        /// Gets the name of the executable of a process. The length of the
+        /// buffer is passed in and the length required is written back.
+        /// Returns `false` if the buffer is too small or the name is
+        /// unavailable.
+        pub fn process_get_name(
+            process: ProcessId,
+            buf_ptr: *mut u8,
+            buf_len_ptr: *mut usize,
+        ) -> bool;
+        /// Gets the path of the executable of a process. The length of the
+        /// buffer is passed in and the length required is written back.
+        /// Returns `false` if the buffer is too small or the path is
+        /// unavailable.
+        pub fn process_get_path(
+            process: ProcessId,
+            buf_ptr: *mut u8,
+            buf_len_ptr: *mut usize,
+        ) -> bool;
+        /// Lists all modules loaded in a process, including their base address
+        /// and size. The length of the buffer is passed in and the amount of
+        /// modules is written back. Returns `false` if the buffer is too small
+        /// or the modules can't be enumerated.
+        pub fn process_list_modules(
+            process: ProcessId,
+            buf_ptr: *mut ModuleRecord,
+            buf_len_ptr: *mut usize,
+        ) -> bool;
+        /// Lists the committed memory ranges of a process. The length of the
+        /// buffer is passed in and the amount of ranges is written back.
+        /// Returns `false` if the buffer is too small or the ranges can't be
+        /// enumerated.
+        pub fn process_list_memory_ranges(
+            process: ProcessId,
+            buf_ptr: *mut MemoryRange,
+            buf_len_ptr: *mut usize,
+        ) -> bool;
+        /// Gets the size of a pointer in a process in bytes. Returns 0 if it
+        /// can't be determined.
+        pub fn process_get_pointer_size(process: ProcessId) -> u32;
         /// Gets the address of a module in a process.
         pub fn process_get_module_address(
             process: ProcessId,
             name_ptr: *const u8,
             name_len: usize,
         ) -> Option<NonZeroAddress>;
+        /// Gets the size of a module in a process.
+        pub fn process_get_module_size(
+            process: ProcessId,
+            name_ptr: *const u8,
+            name_len: usize,
+        ) -> Option<NonZeroU64>;
+        /// Gets the address of the main module of a process.
+        pub fn process_get_main_module_address(process: ProcessId) -> Option<NonZeroAddress>;
+        /// Gets the size of the main module of a process.
+        pub fn process_get_main_module_size(process: ProcessId) -> Option<NonZeroU64>;
         pub fn process_scan_signature(
             process: ProcessId,
             signature_ptr: *const u8,
             signature_len: usize,
         ) -> Option<NonZeroAddress>;
+        /// Scans for a signature within the memory range given, starting at
+        /// `start` and spanning `len` bytes.
+        pub fn process_scan_signature_in_range(
+            process: ProcessId,
+            signature_ptr: *const u8,
+            signature_len: usize,
+            start: Address,
+            len: u64,
+        ) -> Option<NonZeroAddress>;
+        /// Scans for all the matches of a signature. The matches are written to
+        /// the buffer given. The length of the buffer is passed in and the
+        /// amount of matches is written back. Returns `false` if the buffer is
+        /// too small or the signature is malformed.
+        pub fn process_scan_signature_all(
+            process: ProcessId,
+            signature_ptr: *const u8,
+            signature_len: usize,
+            buf_ptr: *mut Address,
+            buf_len_ptr: *mut usize,
+        ) -> bool;
+        /// Scans for a pattern of bytes. Only the bytes where the mask is set
+        /// need to match.
+        pub fn process_scan_masked(
+            process: ProcessId,
+            bytes_ptr: *const u8,
+            mask_ptr: *const u8,
+            len: usize,
+        ) -> Option<NonZeroAddress>;
+        /// Scans for a pattern of bytes within the memory range given, starting
+        /// at `start` and spanning `range_len` bytes. Only the bytes where the
+        /// mask is set need to match.
+        pub fn process_scan_masked_in_range(
+            process: ProcessId,
+            bytes_ptr: *const u8,
+            mask_ptr: *const u8,
+            len: usize,
+            start: Address,
+            range_len: u64,
+        ) -> Option<NonZeroAddress>;
+
+        /// Adds a new boolean setting that the user can modify. Adding a setting
+        /// with a key that already exists keeps the existing setting and its
+        /// value.
+        pub fn user_settings_add_bool(
+            key_ptr: *const u8,
+            key_len: usize,
+            description_ptr: *const u8,
+            description_len: usize,
+            default_value: bool,
+        );
+        /// Gets the current value of a boolean setting. Returns `false` if the
+        /// setting doesn't exist.
+        pub fn user_settings_get_bool(key_ptr: *const u8, key_len: usize) -> bool;
 
         /// Sets the tick rate of the runtime. This influences the amount of
         /// times the `update` function is called per second.
         pub fn runtime_set_tick_rate(ticks_per_second: f64);
+        /// Gets the tick rate that the runtime actually applied, which may be
+        /// clamped. Returns `0` if the runtime can't report it.
+        pub fn runtime_get_tick_rate() -> f64;
+        /// Blocks until the next tick is due. The time spent waiting counts as
+        /// the current tick.
+        pub fn runtime_wait_for_next_tick();
+        /// Gets the time that passed since an unspecified point in time. The
+        /// time never goes backwards. Returns `false` if the runtime has no
+        /// clock.
+        pub fn runtime_get_time(secs_ptr: *mut i64, nanos_ptr: *mut i32) -> bool;
+        /// Lists all running processes, one per line, each as the process id
+        /// followed by a space and the name of the process. The length of the
+        /// buffer is passed in and the length required is written back.
+        /// Returns `false` if the buffer is too small or the processes can't be
+        /// enumerated.
+        pub fn runtime_list_processes(buf_ptr: *mut u8, buf_len_ptr: *mut usize) -> bool;
+        /// Sets a short status message describing what the auto splitter is
+        /// doing, such as waiting for the game or failing to find its offsets.
+        /// The host may show this to the user.
+        pub fn runtime_set_status(text_ptr: *const u8, text_len: usize);
         /// Prints a log message for debugging purposes.
         pub fn runtime_print_message(text_ptr: *const u8, text_len: usize);
     }
 }
 
-pub struct Error;
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Reading `len` bytes of memory at the address given failed.
+    ProcessRead { address: Address, len: usize },
+    /// Writing `len` bytes of memory at the address given failed.
+    ProcessWrite { address: Address, len: usize },
+    /// The module is not loaded in the process.
+    ModuleNotFound,
+    /// The signature could not be found in the memory scanned.
+    SignatureNotFound,
+    /// The signature is not formatted correctly.
+    MalformedSignature,
+    /// The pointer path does not contain any offsets.
+    EmptyPath,
+    /// No null terminator was found within the maximum length.
+    MissingTerminator,
+    /// The size of a pointer in the process could not be determined.
+    UnknownPointerSize,
+    /// The information requested is not available.
+    Unavailable,
+    /// The process has been closed.
+    ProcessClosed,
+    /// A pointer in a pointer path turned out to be null.
+    NullPointer,
+    /// Reading the pointer at the address given failed while resolving a
+    /// pointer path.
+    PathResolution { address: Address },
+    /// The value read doesn't correspond to any variant of the enum.
+    UnknownDiscriminant { value: u32 },
+    /// Reading a slice of `len` bytes at the address given failed, after only
+    /// the first `offset` bytes could be read.
+    SliceRead {
+        address: Address,
+        offset: usize,
+        len: usize,
+    },
+}
 
-#[derive(Debug, PartialEq, Eq)]
-#[repr(transparent)]
-pub struct Process(ProcessId);
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ProcessRead { address, len } => {
+                write!(f, "failed to read {len} bytes at {address}")
+            }
+            Self::ProcessWrite { address, len } => {
+                write!(f, "failed to write {len} bytes at {address}")
+            }
+            Self::ModuleNotFound => f.write_str("the module could not be found"),
+            Self::SignatureNotFound => f.write_str("the signature could not be found"),
+            Self::MalformedSignature => f.write_str("the signature is malformed"),
+            Self::EmptyPath => f.write_str("the pointer path is empty"),
+            Self::MissingTerminator => f.write_str("no null terminator was found"),
+            Self::UnknownPointerSize => f.write_str("the pointer size is unknown"),
+            Self::Unavailable => f.write_str("the information is not available"),
+            Self::ProcessClosed => f.write_str("the process has been closed"),
+            Self::NullPointer => f.write_str("encountered a null pointer"),
+            Self::PathResolution { address } => {
+                write!(f, "failed to resolve the pointer at {address}")
+            }
+            Self::UnknownDiscriminant { value } => write!(f, "unknown discriminant {value}"),
+            Self::SliceRead {
+                address,
+                offset,
+                len,
+            } => write!(
+                f,
+                "failed to read {len} bytes at {address}, only {offset} bytes could be read"
+            ),
+        }
+    }
+}
+
+/// A process the auto splitter is attached to. The second field remembers
+/// whether the process is known to be closed.
+#[derive(Debug)]
+pub struct Process(ProcessId, AtomicBool);
+
+impl PartialEq for Process {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Process {}
 
 impl Drop for Process {
     fn drop(&mut self) {
@@ -115,9 +402,54 @@ impl Drop for Process {
 }
 
 impl Process {
+    fn from_id(id: ProcessId) -> Self {
+        Self(id, AtomicBool::new(false))
+    }
+
     pub fn attach(name: &str) -> Option<Self> {
         let id = unsafe { sys::process_attach(name.as_ptr(), name.len()) };
-        id.map(Self)
+        id.map(Self::from_id)
+    }
+
+    pub fn attach_by_pid(pid: u64) -> Option<Self> {
+        let id = unsafe { sys::process_attach_by_pid(pid) };
+        id.map(Self::from_id)
+    }
+
+    pub fn attach_any(names: &[&str]) -> Option<Self> {
+        names.iter().find_map(|name| Self::attach(name))
+    }
+
+    /// Gets the raw handle of the process used by the runtime.
+    pub fn id(&self) -> u64 {
+        (self.0).0.get()
+    }
+
+    /// Creates a process from a raw handle, returning `None` if the handle is
+    /// zero. The process detaches when dropped, just like an attached one.
+    ///
+    /// # Safety
+    ///
+    /// The handle needs to refer to a process attached by the runtime that is
+    /// not owned by any other [`Process`], as otherwise it gets detached more
+    /// than once.
+    pub unsafe fn from_raw(id: u64) -> Option<Self> {
+        NonZeroU64::new(id).map(|id| Self::from_id(ProcessId(id)))
+    }
+
+    /// Retries attaching to the process up to `max_ticks` times. Each failed
+    /// attempt waits for the next tick before retrying, so every retry
+    /// consumes one tick.
+    pub fn wait_attach(name: &str, max_ticks: u32) -> Option<Self> {
+        for tick in 0..max_ticks {
+            if tick != 0 {
+                unsafe { sys::runtime_wait_for_next_tick() }
+            }
+            if let Some(process) = Self::attach(name) {
+                return Some(process);
+            }
+        }
+        None
     }
 
     pub fn get_module(&self, name: &str) -> Result<Address, Error> {
@@ -126,32 +458,335 @@ impl Process {
             if let Some(address) = address {
                 Ok(Address(address.0.get()))
             } else {
-                Err(Error)
+                Err(Error::ModuleNotFound)
+            }
+        }
+    }
+
+    pub fn get_module_range(&self, name: &str) -> Result<(Address, u64), Error> {
+        let address = self.get_module(name)?;
+        unsafe {
+            let size = sys::process_get_module_size(self.0, name.as_ptr(), name.len());
+            if let Some(size) = size {
+                Ok((address, size.get()))
+            } else {
+                Err(Error::ModuleNotFound)
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn modules(&self) -> Result<Vec<(String, Address, u64)>, Error> {
+        let modules = fill_host_buf(|buf_ptr, buf_len| unsafe {
+            sys::process_list_modules(self.0, buf_ptr, buf_len)
+        })
+        .ok_or(Error::Unavailable)?;
+        Ok(modules
+            .into_iter()
+            .map(|module| {
+                let name_len = (module.name_len as usize).min(sys::MODULE_NAME_LEN);
+                let name = String::from_utf8_lossy(&module.name[..name_len]).into_owned();
+                (name, module.base, module.size)
+            })
+            .collect())
+    }
+
+    /// Finds the module whose memory contains the address given.
+    #[cfg(feature = "alloc")]
+    pub fn module_containing(&self, address: Address) -> Option<(String, Address, u64)> {
+        self.modules()
+            .ok()?
+            .into_iter()
+            .find(|&(_, base, size)| address.0.wrapping_sub(base.0) < size)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn memory_ranges(&self) -> Result<Vec<(Address, u64)>, Error> {
+        let ranges = fill_host_buf(|buf_ptr, buf_len| unsafe {
+            sys::process_list_memory_ranges(self.0, buf_ptr, buf_len)
+        })
+        .ok_or(Error::Unavailable)?;
+        Ok(ranges
+            .into_iter()
+            .filter(|range| range.flags.0 & sys::MemoryRangeFlags::READ != 0)
+            .map(|range| (range.address, range.size))
+            .collect())
+    }
+
+    /// Lazily scans every readable memory range for the signature, yielding
+    /// all the matches. Ranges that fail to be scanned are skipped.
+    #[cfg(feature = "alloc")]
+    pub fn scan_all_ranges<'a>(
+        &'a self,
+        signature: &'a Signature,
+    ) -> impl Iterator<Item = Address> + 'a {
+        let ranges = self.memory_ranges().unwrap_or_default();
+        ranges.into_iter().flat_map(move |(start, len)| {
+            let end = start.0.saturating_add(len);
+            let mut cursor = start.0;
+            iter::from_fn(move || {
+                if cursor >= end {
+                    return None;
+                }
+                let address = self
+                    .scan_in_range(signature, Address(cursor), end - cursor)
+                    .ok()?;
+                cursor = address.0.saturating_add(1);
+                Some(address)
+            })
+        })
+    }
+
+    pub fn main_module(&self) -> Result<(Address, u64), Error> {
+        unsafe {
+            let address = sys::process_get_main_module_address(self.0);
+            let size = sys::process_get_main_module_size(self.0);
+            if let (Some(address), Some(size)) = (address, size) {
+                Ok((Address(address.0.get()), size.get()))
+            } else {
+                Err(Error::ModuleNotFound)
             }
         }
     }
 
+    #[cfg(feature = "alloc")]
+    pub fn module(&self, name: &str) -> Result<Module, Error> {
+        let (base, size) = self.get_module_range(name)?;
+        Ok(Module {
+            name: String::from(name),
+            base,
+            size,
+        })
+    }
+
     pub fn scan_signature(&self, signature: &str) -> Result<Address, Error> {
         unsafe {
             let address = sys::process_scan_signature(self.0, signature.as_ptr(), signature.len());
             if let Some(address) = address {
                 Ok(Address(address.0.get()))
             } else {
-                Err(Error)
+                Err(Error::SignatureNotFound)
+            }
+        }
+    }
+
+    pub fn scan_signature_in_range(
+        &self,
+        signature: &str,
+        start: Address,
+        len: u64,
+    ) -> Result<Address, Error> {
+        if len == 0 {
+            return Err(Error::SignatureNotFound);
+        }
+        unsafe {
+            let address = sys::process_scan_signature_in_range(
+                self.0,
+                signature.as_ptr(),
+                signature.len(),
+                start,
+                len,
+            );
+            if let Some(address) = address {
+                Ok(Address(address.0.get()))
+            } else {
+                Err(Error::SignatureNotFound)
+            }
+        }
+    }
+
+    /// Scans for a signature only within the memory of the module given.
+    pub fn scan_signature_in_module(
+        &self,
+        module: &str,
+        signature: &str,
+    ) -> Result<Address, Error> {
+        let (base, size) = self.get_module_range(module)?;
+        self.scan_signature_in_range(signature, base, size)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn scan_signature_all(&self, signature: &str) -> Result<Vec<Address>, Error> {
+        fill_host_buf(|buf_ptr, buf_len| unsafe {
+            sys::process_scan_signature_all(
+                self.0,
+                signature.as_ptr(),
+                signature.len(),
+                buf_ptr,
+                buf_len,
+            )
+        })
+        .ok_or(Error::MalformedSignature)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn scan(&self, signature: &Signature) -> Result<Address, Error> {
+        let (bytes, mask) = (signature.bytes(), signature.mask());
+        unsafe {
+            let address =
+                sys::process_scan_masked(self.0, bytes.as_ptr(), mask.as_ptr(), bytes.len());
+            if let Some(address) = address {
+                Ok(Address(address.0.get()))
+            } else {
+                Err(Error::SignatureNotFound)
+            }
+        }
+    }
+
+    /// Scans for each of the signatures, such as all the landmarks needed right
+    /// after attaching. The results are in the same order as the signatures,
+    /// with `None` for the signatures that weren't found.
+    #[cfg(feature = "alloc")]
+    pub fn scan_signatures(&self, signatures: &[&Signature]) -> Vec<Option<Address>> {
+        signatures
+            .iter()
+            .map(|signature| self.scan(signature).ok())
+            .collect()
+    }
+
+    /// Scans for the first match of the signature whose address is a multiple
+    /// of `alignment`. An alignment of `0` or `1` behaves like
+    /// [`scan`](Self::scan).
+    #[cfg(feature = "alloc")]
+    pub fn scan_signature_aligned(
+        &self,
+        signature: &Signature,
+        alignment: usize,
+    ) -> Result<Address, Error> {
+        let alignment = alignment.max(1) as u64;
+        let mut address = self.scan(signature)?;
+        while address.0 % alignment != 0 {
+            let start = (address.0 / alignment)
+                .checked_add(1)
+                .and_then(|n| n.checked_mul(alignment))
+                .ok_or(Error::SignatureNotFound)?;
+            address = self.scan_in_range(signature, Address(start), u64::MAX - start)?;
+        }
+        Ok(address)
+    }
+
+    /// Scans for the signature and resolves the relative displacement of the
+    /// instruction found. The `i32` displacement is read at `rel_offset_pos`
+    /// bytes into the match and is relative to the end of the instruction,
+    /// which is `instr_len` bytes long.
+    #[cfg(feature = "alloc")]
+    pub fn scan_and_resolve_rel(
+        &self,
+        signature: &Signature,
+        rel_offset_pos: usize,
+        instr_len: usize,
+    ) -> Result<Address, Error> {
+        let address = self.scan(signature)?;
+        let rel: i32 = self.read(address.offset(rel_offset_pos as i64))?;
+        Ok(address.offset(instr_len as i64 + rel as i64))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn scan_in_range(
+        &self,
+        signature: &Signature,
+        start: Address,
+        len: u64,
+    ) -> Result<Address, Error> {
+        if len == 0 {
+            return Err(Error::SignatureNotFound);
+        }
+        let (bytes, mask) = (signature.bytes(), signature.mask());
+        unsafe {
+            let address = sys::process_scan_masked_in_range(
+                self.0,
+                bytes.as_ptr(),
+                mask.as_ptr(),
+                bytes.len(),
+                start,
+                len,
+            );
+            if let Some(address) = address {
+                Ok(Address(address.0.get()))
+            } else {
+                Err(Error::SignatureNotFound)
             }
         }
     }
 
+    /// Scans for the signature within `window` bytes before and after the
+    /// anchor, such as a match of another signature. The window is clamped to
+    /// the readable memory ranges, and the lowest match within it is returned.
+    #[cfg(feature = "alloc")]
+    pub fn scan_signature_near(
+        &self,
+        signature: &Signature,
+        anchor: Address,
+        window: u64,
+    ) -> Result<Address, Error> {
+        let start = anchor.0.saturating_sub(window);
+        let end = anchor.0.saturating_add(window);
+        self.memory_ranges()?
+            .into_iter()
+            .filter_map(|(address, len)| {
+                let range_start = address.0.max(start);
+                let range_end = address.0.saturating_add(len).min(end);
+                if range_start >= range_end {
+                    return None;
+                }
+                self.scan_in_range(signature, Address(range_start), range_end - range_start)
+                    .ok()
+            })
+            .min()
+            .ok_or(Error::SignatureNotFound)
+    }
+
     pub fn read_into_buf(&self, address: Address, buf: &mut [u8]) -> Result<(), Error> {
+        self.check_not_closed()?;
         unsafe {
             if sys::process_read(self.0, address, buf.as_mut_ptr(), buf.len()) {
                 Ok(())
             } else {
-                Err(Error)
+                Err(Error::ProcessRead {
+                    address,
+                    len: buf.len(),
+                })
             }
         }
     }
 
+    /// Reads several disjoint ranges of memory. The bytes of each range are
+    /// written to `out` one after another, in the same order as the requests.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than the lengths of all the requests combined.
+    pub fn read_batch(&self, requests: &[(Address, usize)], out: &mut [u8]) -> Result<(), Error> {
+        let mut out = out;
+        for &(address, len) in requests {
+            let (buf, rest) = out.split_at_mut(len);
+            self.read_into_buf(address, buf)?;
+            out = rest;
+        }
+        Ok(())
+    }
+
+    /// Reads as many bytes as possible into the buffer, returning how many
+    /// bytes from the start of the buffer were read. This is useful for reads
+    /// that may cross into unreadable memory. Reading nothing at all for a
+    /// non-empty buffer is treated as an error.
+    pub fn read_partial(&self, address: Address, buf: &mut [u8]) -> Result<usize, Error> {
+        self.check_not_closed()?;
+        let len =
+            unsafe { sys::process_read_partial(self.0, address, buf.as_mut_ptr(), buf.len()) };
+        if len == 0 && !buf.is_empty() {
+            Err(Error::ProcessRead {
+                address,
+                len: buf.len(),
+            })
+        } else {
+            Ok(len)
+        }
+    }
+
+    /// Reads a value from the address given. The bytes are copied into
+    /// properly aligned memory on this side, so the address in the process
+    /// does not need to be aligned for `T`.
     pub fn read<T: Pod>(&self, address: Address) -> Result<T, Error> {
         unsafe {
             let mut value = MaybeUninit::<T>::uninit();
@@ -163,117 +798,1428 @@ impl Process {
         }
     }
 
-    pub fn read_pointer_path64<T: Pod>(&self, mut address: u64, path: &[u64]) -> Result<T, Error> {
-        let (&last, path) = path.split_last().ok_or(Error)?;
-        for &offset in path {
-            address = self.read(Address(address.wrapping_add(offset)))?;
-        }
-        self.read(Address(address.wrapping_add(last)))
+    pub fn try_read<T: Pod>(&self, address: Address) -> Option<T> {
+        self.read(address).ok()
     }
 
-    pub fn read_pointer_path32<T: Pod>(&self, mut address: u32, path: &[u32]) -> Result<T, Error> {
-        let (&last, path) = path.split_last().ok_or(Error)?;
-        for &offset in path {
-            address = self.read(Address(address.wrapping_add(offset) as u64))?;
+    /// Reads a `u32` and converts it into an enum. Values that the enum doesn't
+    /// recognize are reported as [`Error::UnknownDiscriminant`].
+    pub fn read_enum<T: TryFrom<u32>>(&self, address: Address) -> Result<T, Error> {
+        let value: u32 = self.read(address)?;
+        T::try_from(value).map_err(|_| Error::UnknownDiscriminant { value })
+    }
+
+    /// Reads a single byte and treats any value other than zero as `true`.
+    pub fn read_bool(&self, address: Address) -> Result<bool, Error> {
+        self.read::<u8>(address).map(|byte| byte != 0)
+    }
+
+    /// Reads an 8 byte pointer, such as a single field of a structure.
+    pub fn read_ptr64(&self, address: Address) -> Result<Address, Error> {
+        self.read::<u64>(address).map(Address)
+    }
+
+    /// Reads a 4 byte pointer and zero-extends it to an [`Address`].
+    pub fn read_ptr32(&self, address: Address) -> Result<Address, Error> {
+        self.read::<u32>(address).map(Address::from)
+    }
+
+    /// Reads a `u32` of bit flags. Use [`Flags`] or [`flag_set`] to check
+    /// individual bits.
+    pub fn read_flags(&self, address: Address) -> Result<u32, Error> {
+        self.read(address)
+    }
+
+    /// Reads three contiguous components, such as the position of the player.
+    pub fn read_vec3<T: Pod>(&self, address: Address) -> Result<Vec3<T>, Error> {
+        self.read(address)
+    }
+
+    /// Reads a value and losslessly converts it into a wider type, such as
+    /// reading an `f32` as an `f64`.
+    pub fn read_widen<T: Pod, U: From<T>>(&self, address: Address) -> Result<U, Error> {
+        self.read::<T>(address).map(U::from)
+    }
+
+    pub fn read_f32_as_f64(&self, address: Address) -> Result<f64, Error> {
+        self.read_widen::<f32, f64>(address)
+    }
+
+    /// Reads a value from any address, no matter its alignment. This is the
+    /// same as [`read`](Self::read), but makes it explicit that the value may
+    /// be part of a packed structure.
+    pub fn read_unaligned<T: Pod>(&self, address: Address) -> Result<T, Error> {
+        self.read(address)
+    }
+
+    /// Reads a value, retrying up to `attempts` times within the same tick if
+    /// the read fails. An amount of `0` attempts is treated as a single
+    /// attempt.
+    pub fn read_retry<T: Pod>(&self, address: Address, attempts: u32) -> Result<T, Error> {
+        let mut result = self.read(address);
+        for _ in 1..attempts {
+            if result.is_ok() {
+                break;
+            }
+            result = self.read(address);
         }
-        self.read(Address(address.wrapping_add(last) as u64))
+        result
     }
 
-    pub fn read_into_slice<T: Pod>(&self, address: Address, slice: &mut [T]) -> Result<(), Error> {
-        self.read_into_buf(address, bytemuck::cast_slice_mut(slice))
+    pub fn read_array<T: Pod, const N: usize>(&self, address: Address) -> Result<[T; N], Error> {
+        let mut values = [T::zeroed(); N];
+        self.read_into_slice(address, &mut values)?;
+        Ok(values)
     }
 
-    pub fn is_open(&self) -> bool {
-        unsafe { sys::process_is_open(self.0) }
+    /// Reads a value stored in the byte order `E`, such as [`BigEndian`] for
+    /// games running on emulated consoles. [`read`](Self::read) is the same as
+    /// reading as [`LittleEndian`]. Only single [`Scalar`] values can be read
+    /// this way, as the bytes of each element need to be swapped separately.
+    /// Use [`read_array_as`](Self::read_array_as) for arrays.
+    pub fn read_as<E: Endian, T: Scalar>(&self, address: Address) -> Result<T, Error> {
+        self.read(address).map(E::to_native)
     }
-}
 
-impl From<u32> for Address {
-    fn from(addr: u32) -> Self {
-        Self(addr as u64)
+    pub fn read_be<T: Scalar>(&self, address: Address) -> Result<T, Error> {
+        self.read_as::<BigEndian, T>(address)
     }
-}
 
-impl From<u64> for Address {
-    fn from(addr: u64) -> Self {
-        Self(addr)
+    /// Reads an array whose elements are stored in the byte order `E`.
+    pub fn read_array_as<E: Endian, T: Scalar, const N: usize>(
+        &self,
+        address: Address,
+    ) -> Result<[T; N], Error> {
+        let mut values = self.read_array(address)?;
+        slice_to_native::<E, T>(&mut values);
+        Ok(values)
     }
-}
 
-impl Add<u32> for Address {
-    type Output = Self;
+    /// Reads the elements of the slice, which are stored in the byte order `E`.
+    pub fn read_into_slice_as<E: Endian, T: Scalar>(
+        &self,
+        address: Address,
+        slice: &mut [T],
+    ) -> Result<(), Error> {
+        self.read_into_slice(address, slice)?;
+        slice_to_native::<E, T>(slice);
+        Ok(())
+    }
 
-    fn add(self, rhs: u32) -> Self::Output {
-        Self(self.0 + rhs as u64)
+    /// Reads three contiguous components stored in the byte order `E`.
+    pub fn read_vec3_as<E: Endian, T: Scalar>(&self, address: Address) -> Result<Vec3<T>, Error> {
+        let [x, y, z] = self.read_array_as::<E, T, 3>(address)?;
+        Ok(Vec3 { x, y, z })
     }
-}
 
-impl Add<u64> for Address {
-    type Output = Self;
+    pub fn read_pointer_path64<T: Pod>(&self, address: u64, path: &[u64]) -> Result<T, Error> {
+        self.read(self.resolve_pointer_path64(address, path)?)
+    }
 
-    fn add(self, rhs: u64) -> Self::Output {
-        Self(self.0 + rhs)
+    /// Like [`read_pointer_path64`](Self::read_pointer_path64), but starts at an
+    /// [`Address`], such as one returned by [`get_module`](Self::get_module).
+    pub fn read_pointer_path64_at<T: Pod>(&self, base: Address, path: &[u64]) -> Result<T, Error> {
+        self.read_pointer_path64(base.0, path)
     }
-}
 
-pub mod timer {
-    use super::sys;
+    pub fn try_read_pointer_path64<T: Pod>(&self, address: u64, path: &[u64]) -> Option<T> {
+        self.read_pointer_path64(address, path).ok()
+    }
 
-    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-    pub enum TimerState {
-        NotRunning,
-        Running,
-        Paused,
-        Ended,
+    pub fn read_pointer_path_into_buf(
+        &self,
+        address: u64,
+        path: &[u64],
+        buf: &mut [u8],
+    ) -> Result<(), Error> {
+        self.read_into_buf(self.resolve_pointer_path64(address, path)?, buf)
     }
 
-    pub fn start() {
-        unsafe { sys::timer_start() }
+    /// Resolves the address a pointer path points to. Every offset but the last
+    /// is added and then dereferenced. The last offset is added after the
+    /// final dereference, so the address returned is where
+    /// [`read_pointer_path64`](Self::read_pointer_path64) would read the value
+    /// from.
+    pub fn resolve_pointer_path64(&self, mut address: u64, path: &[u64]) -> Result<Address, Error> {
+        let (&last, path) = path.split_last().ok_or(Error::EmptyPath)?;
+        for &offset in path {
+            address = self.read(Address(address.wrapping_add(offset)))?;
+            if address == 0 {
+                return Err(Error::NullPointer);
+            }
+        }
+        Ok(Address(address.wrapping_add(last)))
     }
 
-    pub fn split() {
-        unsafe { sys::timer_split() }
+    /// Like [`resolve_pointer_path64`](Self::resolve_pointer_path64), but
+    /// starts at an [`Address`].
+    pub fn resolve_pointer_path64_at(&self, base: Address, path: &[u64]) -> Result<Address, Error> {
+        self.resolve_pointer_path64(base.0, path)
     }
 
-    pub fn reset() {
-        unsafe { sys::timer_reset() }
+    pub fn read_pointer_path_from_module<T: Pod>(
+        &self,
+        module: &str,
+        path: &[u64],
+    ) -> Result<T, Error> {
+        let base = self.get_module(module)?;
+        self.read_pointer_path64_at(base, path)
     }
 
-    pub fn pause_game_time() {
-        unsafe { sys::timer_pause_game_time() }
+    pub fn read_pointer_path32<T: Pod>(&self, address: u32, path: &[u32]) -> Result<T, Error> {
+        self.read(self.resolve_pointer_path32_as::<LittleEndian>(address, path)?)
     }
 
-    pub fn resume_game_time() {
-        unsafe { sys::timer_resume_game_time() }
+    /// Like [`read_pointer_path32`](Self::read_pointer_path32), but the
+    /// pointers and the final value are read as big-endian.
+    pub fn read_pointer_path_be32<T: Scalar>(
+        &self,
+        address: u32,
+        path: &[u32],
+    ) -> Result<T, Error> {
+        self.read_pointer_path32_as::<BigEndian, T>(address, path)
     }
 
-    pub fn set_variable(key: &str, value: &str) {
-        unsafe { sys::timer_set_variable(key.as_ptr(), key.len(), value.as_ptr(), value.len()) }
+    /// Like [`read_pointer_path32`](Self::read_pointer_path32), but the
+    /// pointers and the final value are read in the byte order `E`.
+    pub fn read_pointer_path32_as<E: Endian, T: Scalar>(
+        &self,
+        address: u32,
+        path: &[u32],
+    ) -> Result<T, Error> {
+        self.read_as::<E, T>(self.resolve_pointer_path32_as::<E>(address, path)?)
     }
 
-    pub fn state() -> TimerState {
-        unsafe {
-            match sys::timer_get_state() {
-                sys::TimerState::NOT_RUNNING => TimerState::NotRunning,
-                sys::TimerState::PAUSED => TimerState::Paused,
-                sys::TimerState::RUNNING => TimerState::Running,
-                sys::TimerState::ENDED => TimerState::Ended,
-                _ => core::hint::unreachable_unchecked(),
+    fn resolve_pointer_path32_as<E: Endian>(
+        &self,
+        mut address: u32,
+        path: &[u32],
+    ) -> Result<Address, Error> {
+        let (&last, path) = path.split_last().ok_or(Error::EmptyPath)?;
+        for &offset in path {
+            address = self.read_as::<E, _>(Address(address.wrapping_add(offset) as u64))?;
+            if address == 0 {
+                return Err(Error::NullPointer);
             }
         }
+        Ok(Address(address.wrapping_add(last) as u64))
     }
 
-    pub fn set_game_time(time: time::Duration) {
+    /// Reads a value at the end of a pointer path, reading pointers of the
+    /// width given. A `bool` may be passed instead of a [`PointerWidth`], where
+    /// `true` means 64-bit. In the 32-bit case the base address and the
+    /// offsets are truncated to 32 bits.
+    pub fn read_pointer_path<T: Pod>(
+        &self,
+        base: Address,
+        width: impl Into<PointerWidth>,
+        path: &[u64],
+    ) -> Result<T, Error> {
+        self.read(self.resolve_pointer_path(base, width, path)?)
+    }
+
+    /// Resolves the address a pointer path points to, reading pointers of the
+    /// width given. This is where
+    /// [`read_pointer_path`](Self::read_pointer_path) would read the value
+    /// from.
+    pub fn resolve_pointer_path(
+        &self,
+        base: Address,
+        width: impl Into<PointerWidth>,
+        path: &[u64],
+    ) -> Result<Address, Error> {
+        match width.into() {
+            PointerWidth::Bits64 => self.resolve_pointer_path64_at(base, path),
+            PointerWidth::Bits32 => {
+                let (&last, path) = path.split_last().ok_or(Error::EmptyPath)?;
+                let mut address = base.truncate_to_32().0 as u32;
+                for &offset in path {
+                    address = self.read(Address::from(address.wrapping_add(offset as u32)))?;
+                    if address == 0 {
+                        return Err(Error::NullPointer);
+                    }
+                }
+                Ok(Address::from(address.wrapping_add(last as u32)))
+            }
+        }
+    }
+
+    /// Reads the elements of the slice from the address given. If only part of
+    /// the slice can be read, such as when it straddles unmapped memory, the
+    /// error is an [`Error::SliceRead`] reporting how many bytes were read.
+    pub fn read_into_slice<T: Pod>(&self, address: Address, slice: &mut [T]) -> Result<(), Error> {
+        let buf: &mut [u8] = bytemuck::cast_slice_mut(slice);
+        let mut offset = 0;
+        while offset < buf.len() {
+            match self.read_partial(address + offset as u64, &mut buf[offset..]) {
+                Ok(len) => offset += len,
+                Err(Error::ProcessClosed) => return Err(Error::ProcessClosed),
+                Err(_) => {
+                    return Err(Error::SliceRead {
+                        address,
+                        offset,
+                        len: buf.len(),
+                    })
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the elements at `start + i * stride` until an element equals the
+    /// sentinel or `max` elements were read. The sentinel is not included.
+    #[cfg(feature = "alloc")]
+    pub fn read_until<T: Pod + PartialEq>(
+        &self,
+        start: Address,
+        stride: u64,
+        max: usize,
+        sentinel: T,
+    ) -> Result<Vec<T>, Error> {
+        let mut values = Vec::new();
+        let mut address = start;
+        while values.len() < max {
+            let value: T = self.read(address)?;
+            if value == sentinel {
+                break;
+            }
+            values.push(value);
+            address += stride;
+        }
+        Ok(values)
+    }
+
+    /// Reads into a slice without it needing to be initialized first. On
+    /// success the now initialized slice is returned. On failure the slice may
+    /// be left uninitialized.
+    pub fn read_into_uninit<'a, T: Pod>(
+        &self,
+        address: Address,
+        slice: &'a mut [MaybeUninit<T>],
+    ) -> Result<&'a mut [T], Error> {
         unsafe {
-            sys::timer_set_game_time(time.whole_seconds(), time.subsec_nanoseconds());
+            self.read_into_buf(
+                address,
+                slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), mem::size_of_val(slice)),
+            )?;
+            Ok(&mut *(slice as *mut [MaybeUninit<T>] as *mut [T]))
         }
     }
-}
 
-pub fn set_tick_rate(ticks_per_second: f64) {
-    unsafe { sys::runtime_set_tick_rate(ticks_per_second) }
-}
+    #[cfg(feature = "alloc")]
+    pub fn read_vec<T: Pod>(&self, address: Address, count: usize) -> Result<Vec<T>, Error> {
+        let mut values = vec![T::zeroed(); count];
+        self.read_into_slice(address, &mut values)?;
+        Ok(values)
+    }
 
-pub fn print_message(text: &str) {
-    unsafe { sys::runtime_print_message(text.as_ptr(), text.len()) }
+    /// Reads `count` 8 byte pointers from the array given and then reads a
+    /// value from each pointer plus `final_offset`. Fails if any of the reads
+    /// fail.
+    #[cfg(feature = "alloc")]
+    pub fn read_pointer_array64<T: Pod>(
+        &self,
+        array_addr: Address,
+        count: usize,
+        final_offset: u64,
+    ) -> Result<Vec<T>, Error> {
+        self.read_vec::<u64>(array_addr, count)?
+            .into_iter()
+            .map(|pointer| self.read(Address(pointer.wrapping_add(final_offset))))
+            .collect()
+    }
+
+    /// Like [`read_pointer_array64`](Self::read_pointer_array64), but entries
+    /// that fail to be read are `None` instead of failing the whole read.
+    #[cfg(feature = "alloc")]
+    pub fn read_pointer_array64_opt<T: Pod>(
+        &self,
+        array_addr: Address,
+        count: usize,
+        final_offset: u64,
+    ) -> Result<Vec<Option<T>>, Error> {
+        Ok(self
+            .read_vec::<u64>(array_addr, count)?
+            .into_iter()
+            .map(|pointer| self.read(Address(pointer.wrapping_add(final_offset))).ok())
+            .collect())
+    }
+
+    /// Reads a tick count and converts it into a duration with
+    /// [`timer::ticks_to_duration`].
+    pub fn read_game_timer<T: Pod + Into<u64>>(
+        &self,
+        address: Address,
+        ticks_per_second: f64,
+    ) -> Result<time::Duration, Error> {
+        let ticks: T = self.read(address)?;
+        Ok(timer::ticks_to_duration(ticks.into(), ticks_per_second))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn read_c_str(&self, address: Address, max_len: usize) -> Result<Vec<u8>, Error> {
+        match self.read_until_nul(address, max_len)? {
+            (bytes, true) => Ok(bytes),
+            (_, false) => Err(Error::MissingTerminator),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn read_c_string_lossy(&self, address: Address, max_len: usize) -> Result<String, Error> {
+        let bytes = self.read_c_str(address, max_len)?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    /// Reads a `u32` length followed by that many bytes. Lengths larger than
+    /// `max_len` are clamped to it, which protects against garbage lengths.
+    #[cfg(feature = "alloc")]
+    pub fn read_len_prefixed(&self, address: Address, max_len: usize) -> Result<Vec<u8>, Error> {
+        let len: u32 = self.read(address)?;
+        self.read_vec(address + 4u64, (len as usize).min(max_len))
+    }
+
+    /// Reads a length-prefixed UTF-8 string like [`Process::read_len_prefixed`]
+    /// does. Invalid UTF-8 is replaced lossily.
+    #[cfg(feature = "alloc")]
+    pub fn read_len_prefixed_string(
+        &self,
+        address: Address,
+        max_len: usize,
+    ) -> Result<String, Error> {
+        let bytes = self.read_len_prefixed(address, max_len)?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    /// Reads a UTF-8 string behind a pointer path. The string ends at a null
+    /// terminator or after `max_len` bytes, whichever comes first. Invalid
+    /// UTF-8 is replaced lossily. Failing to read a pointer along the path is
+    /// reported as [`Error::PathResolution`], while failing to read the string
+    /// itself is reported as [`Error::ProcessRead`].
+    #[cfg(feature = "alloc")]
+    pub fn read_utf8_at_path(
+        &self,
+        base: u64,
+        path: &[u64],
+        max_len: usize,
+    ) -> Result<String, Error> {
+        let address = self
+            .resolve_pointer_path64(base, path)
+            .map_err(|error| match error {
+                Error::ProcessRead { address, .. } => Error::PathResolution { address },
+                error => error,
+            })?;
+        let (bytes, _) = self.read_until_nul(address, max_len)?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    /// Like [`read_utf8_at_path`](Self::read_utf8_at_path), but starts at an
+    /// [`Address`].
+    #[cfg(feature = "alloc")]
+    pub fn read_utf8_at_path_at(
+        &self,
+        base: Address,
+        path: &[u64],
+        max_len: usize,
+    ) -> Result<String, Error> {
+        self.read_utf8_at_path(base.0, path, max_len)
+    }
+
+    /// Reads bytes until a null terminator or until `max_len` bytes are read.
+    /// Also returns whether the terminator was found. Only the part of each
+    /// chunk that could be read is searched, so a string that ends shortly
+    /// before unreadable memory can still be read.
+    #[cfg(feature = "alloc")]
+    fn read_until_nul(
+        &self,
+        mut address: Address,
+        max_len: usize,
+    ) -> Result<(Vec<u8>, bool), Error> {
+        let mut buf = [0; 64];
+        let mut bytes = Vec::new();
+        while bytes.len() < max_len {
+            let chunk = &mut buf[..(max_len - bytes.len()).min(64)];
+            let len = self.read_partial(address, chunk)?;
+            let chunk = &chunk[..len];
+            if let Some(len) = chunk.iter().position(|&b| b == 0) {
+                bytes.extend_from_slice(&chunk[..len]);
+                return Ok((bytes, true));
+            }
+            bytes.extend_from_slice(chunk);
+            address += chunk.len() as u64;
+        }
+        Ok((bytes, false))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn read_utf16_str(&self, address: Address, len_in_u16: usize) -> Result<String, Error> {
+        let mut units = vec![0u16; len_in_u16];
+        self.read_into_slice(address, &mut units)?;
+        Ok(decode_utf16_le(&units))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn read_utf16_c_str(
+        &self,
+        address: Address,
+        max_len_in_u16: usize,
+    ) -> Result<String, Error> {
+        let mut buf = [0u16; 64];
+        let mut units = Vec::new();
+        let mut address = address;
+        while units.len() < max_len_in_u16 {
+            let chunk = &mut buf[..(max_len_in_u16 - units.len()).min(64)];
+            // Only the code units that could be read completely are searched,
+            // so a string that ends shortly before unreadable memory can still
+            // be read.
+            let len = self.read_partial(address, bytemuck::cast_slice_mut(chunk))? / 2;
+            if len == 0 {
+                return Err(Error::ProcessRead { address, len: 2 });
+            }
+            let chunk = &chunk[..len];
+            if let Some(len) = chunk.iter().position(|&u| u == 0) {
+                units.extend_from_slice(&chunk[..len]);
+                return Ok(decode_utf16_le(&units));
+            }
+            units.extend_from_slice(chunk);
+            address += 2 * chunk.len() as u64;
+        }
+        Err(Error::MissingTerminator)
+    }
+
+    pub fn write_buf(&self, address: Address, buf: &[u8]) -> Result<(), Error> {
+        if buf.is_empty() {
+            return Ok(());
+        }
+        unsafe {
+            if sys::process_write(self.0, address, buf.as_ptr(), buf.len()) {
+                Ok(())
+            } else {
+                Err(Error::ProcessWrite {
+                    address,
+                    len: buf.len(),
+                })
+            }
+        }
+    }
+
+    pub fn write<T: Pod>(&self, address: Address, value: &T) -> Result<(), Error> {
+        self.write_buf(address, bytemuck::bytes_of(value))
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn name(&self) -> Result<String, Error> {
+        let buf = fill_host_buf(|buf_ptr, buf_len| unsafe {
+            sys::process_get_name(self.0, buf_ptr, buf_len)
+        })
+        .ok_or(Error::Unavailable)?;
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn path(&self) -> Result<String, Error> {
+        let buf = fill_host_buf(|buf_ptr, buf_len| unsafe {
+            sys::process_get_path(self.0, buf_ptr, buf_len)
+        })
+        .ok_or(Error::Unavailable)?;
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+
+    pub fn is_64bit(&self) -> Result<bool, Error> {
+        match unsafe { sys::process_get_pointer_size(self.0) } {
+            4 => Ok(false),
+            8 => Ok(true),
+            _ => Err(Error::UnknownPointerSize),
+        }
+    }
+
+    /// Checks whether the process is still open. Once the process is found to
+    /// be closed, the runtime isn't asked again, and all reads fail with
+    /// [`Error::ProcessClosed`] right away.
+    pub fn is_open(&self) -> bool {
+        if self.1.load(atomic::Ordering::Relaxed) {
+            return false;
+        }
+        let is_open = unsafe { sys::process_is_open(self.0) };
+        if !is_open {
+            self.1.store(true, atomic::Ordering::Relaxed);
+        }
+        is_open
+    }
+
+    fn check_not_closed(&self) -> Result<(), Error> {
+        if self.1.load(atomic::Ordering::Relaxed) {
+            Err(Error::ProcessClosed)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns an error if the process has been closed. This does not detach
+    /// from the process, which happens when it gets dropped.
+    pub fn ensure_open(&self) -> Result<(), Error> {
+        if self.is_open() {
+            Ok(())
+        } else {
+            Err(Error::ProcessClosed)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn fill_host_buf<T: Copy>(mut f: impl FnMut(*mut T, &mut usize) -> bool) -> Option<Vec<T>> {
+    let mut buf = Vec::new();
+    loop {
+        let mut len = buf.capacity();
+        if f(buf.as_mut_ptr(), &mut len) {
+            unsafe { buf.set_len(len) };
+            return Some(buf);
+        }
+        if len <= buf.capacity() {
+            return None;
+        }
+        buf.reserve_exact(len);
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn decode_utf16_le(units: &[u16]) -> String {
+    char::decode_utf16(units.iter().map(|&u| u16::from_le(u)))
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+/// Reads individual fields at offsets from a base address, stopping at the
+/// first read that fails. The fields are either returned as a tuple or used to
+/// construct the struct given:
+///
+/// ```ignore
+/// let (health, x) = asr::read_fields!(&process, base, { health: u32 = 0x10, x: f32 = 0x24 })?;
+/// let player = asr::read_fields!(&process, base, Player { health: u32 = 0x10, x: f32 = 0x24 })?;
+/// ```
+#[macro_export]
+macro_rules! read_fields {
+    ($process:expr, $base:expr, $struct:ident { $($name:ident: $ty:ty = $offset:expr),* $(,)? }) => {
+        $crate::read_fields!($process, $base, { $($name: $ty = $offset),* })
+            .map(|($($name,)*)| $struct { $($name),* })
+    };
+    ($process:expr, $base:expr, { $($name:ident: $ty:ty = $offset:expr),* $(,)? }) => {
+        (|| -> ::core::result::Result<_, $crate::Error> {
+            let process: &$crate::Process = $process;
+            let base: $crate::Address = $base;
+            $(let $name: $ty = process.read(base + ($offset as u64))?;)*
+            Ok(($($name,)*))
+        })()
+    };
+}
+
+/// A module loaded in a process.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Module {
+    pub name: String,
+    pub base: Address,
+    pub size: u64,
+}
+
+#[cfg(feature = "alloc")]
+impl Module {
+    pub fn contains(&self, address: Address) -> bool {
+        address.0.wrapping_sub(self.base.0) < self.size
+    }
+
+    /// Gets the start and the end of the module's memory. The end is
+    /// exclusive.
+    pub fn range(&self) -> (Address, Address) {
+        (self.base, self.base + self.size)
+    }
+}
+
+/// Checks whether the bit at the index given is set. Bits past the width of
+/// the value are never set.
+pub const fn flag_set(value: u32, bit: u32) -> bool {
+    match value.checked_shr(bit) {
+        Some(value) => value & 1 != 0,
+        None => false,
+    }
+}
+
+/// A set of bit flags, such as the objectives completed in a game.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Flags(pub u32);
+
+impl Flags {
+    pub const fn is_set(self, bit: u32) -> bool {
+        flag_set(self.0, bit)
+    }
+}
+
+impl From<u32> for Flags {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+/// A vector of three components, such as a position in a 3D game.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+// SAFETY: All three fields have the same type, so there is no padding between
+// or after them and every bit pattern that is valid for `T` is valid for the
+// whole vector.
+unsafe impl<T: Zeroable> Zeroable for Vec3<T> {}
+unsafe impl<T: Pod> Pod for Vec3<T> {}
+
+// There's no square root in `core`, so only the squared distance is provided.
+// Compare it against the square of a threshold to detect movement.
+impl Vec3<f32> {
+    pub fn distance_squared(self, other: Self) -> f32 {
+        let (x, y, z) = (self.x - other.x, self.y - other.y, self.z - other.z);
+        x * x + y * y + z * z
+    }
+}
+
+impl Vec3<f64> {
+    pub fn distance_squared(self, other: Self) -> f64 {
+        let (x, y, z) = (self.x - other.x, self.y - other.y, self.z - other.z);
+        x * x + y * y + z * z
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A primitive number whose bytes can be swapped as a whole to change its byte
+/// order. This is implemented for all integers up to 64 bits and the floating
+/// point numbers.
+pub trait Scalar: Pod + sealed::Sealed {}
+
+macro_rules! impl_scalar {
+    ($($ty:ty),*) => {
+        $(
+            impl sealed::Sealed for $ty {}
+            impl Scalar for $ty {}
+        )*
+    };
+}
+
+impl_scalar!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+/// The byte order values are stored in within the memory of a process.
+pub trait Endian {
+    /// Converts a value read in this byte order to the native byte order.
+    fn to_native<T: Scalar>(value: T) -> T;
+}
+
+/// Values are stored with the least significant byte first.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct LittleEndian;
+
+/// Values are stored with the most significant byte first.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BigEndian;
+
+impl Endian for LittleEndian {
+    fn to_native<T: Scalar>(value: T) -> T {
+        if cfg!(target_endian = "little") {
+            value
+        } else {
+            swap_bytes(value)
+        }
+    }
+}
+
+impl Endian for BigEndian {
+    fn to_native<T: Scalar>(value: T) -> T {
+        if cfg!(target_endian = "big") {
+            value
+        } else {
+            swap_bytes(value)
+        }
+    }
+}
+
+fn swap_bytes<T: Scalar>(mut value: T) -> T {
+    bytemuck::bytes_of_mut(&mut value).reverse();
+    value
+}
+
+fn slice_to_native<E: Endian, T: Scalar>(values: &mut [T]) {
+    for value in values {
+        *value = E::to_native(*value);
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PointerWidth {
+    Bits32,
+    Bits64,
+}
+
+impl From<bool> for PointerWidth {
+    fn from(is_64bit: bool) -> Self {
+        if is_64bit {
+            Self::Bits64
+        } else {
+            Self::Bits32
+        }
+    }
+}
+
+impl fmt::Debug for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{:016X}", self.0)
+    }
+}
+
+impl Address {
+    pub const fn raw(self) -> u64 {
+        self.0
+    }
+
+    pub const fn is_null(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Keeps only the lower 32 bits of the address.
+    pub const fn truncate_to_32(self) -> Self {
+        Self(self.0 as u32 as u64)
+    }
+
+    pub const fn checked_add(self, rhs: u64) -> Option<Self> {
+        match self.0.checked_add(rhs) {
+            Some(address) => Some(Self(address)),
+            None => None,
+        }
+    }
+
+    pub const fn wrapping_add(self, rhs: u64) -> Self {
+        Self(self.0.wrapping_add(rhs))
+    }
+
+    /// Offsets the address by a signed amount, wrapping around at the
+    /// boundaries of the address space.
+    pub const fn offset(self, delta: i64) -> Self {
+        Self(self.0.wrapping_add(delta as u64))
+    }
+
+    /// Offsets the address by a signed amount, returning `None` if the result
+    /// would be outside of the address space.
+    pub const fn checked_offset(self, delta: i64) -> Option<Self> {
+        match self.0.checked_add_signed(delta) {
+            Some(address) => Some(Self(address)),
+            None => None,
+        }
+    }
+}
+
+impl NonZeroAddress {
+    pub const fn new(address: Address) -> Option<Self> {
+        match NonZeroU64::new(address.0) {
+            Some(address) => Some(Self(address)),
+            None => None,
+        }
+    }
+
+    pub const fn get(self) -> Address {
+        Address(self.0.get())
+    }
+}
+
+impl From<NonZeroAddress> for Address {
+    fn from(address: NonZeroAddress) -> Self {
+        address.get()
+    }
+}
+
+impl From<u32> for Address {
+    fn from(addr: u32) -> Self {
+        Self(addr as u64)
+    }
+}
+
+impl From<u64> for Address {
+    fn from(addr: u64) -> Self {
+        Self(addr)
+    }
+}
+
+// There's intentionally no `PartialEq<u32>`, as a second integer impl would
+// make comparisons against untyped literals like `address == 0` ambiguous.
+impl PartialEq<u64> for Address {
+    fn eq(&self, other: &u64) -> bool {
+        self.0 == *other
+    }
+}
+
+impl Add<u32> for Address {
+    type Output = Self;
+
+    fn add(self, rhs: u32) -> Self::Output {
+        Self(self.0.wrapping_add(rhs as u64))
+    }
+}
+
+impl Add<u64> for Address {
+    type Output = Self;
+
+    fn add(self, rhs: u64) -> Self::Output {
+        Self(self.0.wrapping_add(rhs))
+    }
+}
+
+impl AddAssign<u32> for Address {
+    fn add_assign(&mut self, rhs: u32) {
+        self.0 = self.0.wrapping_add(rhs as u64);
+    }
+}
+
+impl AddAssign<u64> for Address {
+    fn add_assign(&mut self, rhs: u64) {
+        self.0 = self.0.wrapping_add(rhs);
+    }
+}
+
+impl Sub<u32> for Address {
+    type Output = Self;
+
+    fn sub(self, rhs: u32) -> Self::Output {
+        Self(self.0.wrapping_sub(rhs as u64))
+    }
+}
+
+impl Sub<u64> for Address {
+    type Output = Self;
+
+    fn sub(self, rhs: u64) -> Self::Output {
+        Self(self.0.wrapping_sub(rhs))
+    }
+}
+
+impl Sub<Address> for Address {
+    type Output = u64;
+
+    fn sub(self, rhs: Address) -> Self::Output {
+        self.0.wrapping_sub(rhs.0)
+    }
+}
+
+pub mod timer {
+    use super::{sys, FormatBuf};
+    use crate::watcher::{Pair, Watcher};
+    use core::fmt;
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum TimerState {
+        NotRunning,
+        Running,
+        Paused,
+        Ended,
+    }
+
+    /// Watches the state of the timer to detect transitions, such as the run
+    /// ending or the user resetting. Update it every tick with
+    /// `watcher.update_infallible(timer::state())`.
+    pub type StateWatcher = Watcher<TimerState>;
+
+    impl Pair<TimerState> {
+        pub fn just_started(&self) -> bool {
+            self.old == TimerState::NotRunning && self.current != TimerState::NotRunning
+        }
+
+        pub fn just_ended(&self) -> bool {
+            self.changed_to(&TimerState::Ended)
+        }
+
+        pub fn just_reset(&self) -> bool {
+            self.old != TimerState::NotRunning && self.current == TimerState::NotRunning
+        }
+    }
+
+    pub fn start() {
+        unsafe { sys::timer_start() }
+    }
+
+    pub fn split() {
+        unsafe { sys::timer_split() }
+    }
+
+    pub fn reset() {
+        unsafe { sys::timer_reset() }
+    }
+
+    pub fn skip_split() {
+        unsafe { sys::timer_skip_split() }
+    }
+
+    pub fn undo_split() {
+        unsafe { sys::timer_undo_split() }
+    }
+
+    /// Pauses the timer itself, which puts it into the [`TimerState::Paused`]
+    /// state. Use [`pause_game_time`] to only stop the game time from
+    /// advancing.
+    pub fn pause() {
+        unsafe { sys::timer_pause() }
+    }
+
+    /// Resumes the timer after it got paused with [`pause`]. Use
+    /// [`resume_game_time`] to only resume the game time.
+    pub fn resume() {
+        unsafe { sys::timer_resume() }
+    }
+
+    pub fn pause_game_time() {
+        unsafe { sys::timer_pause_game_time() }
+    }
+
+    pub fn resume_game_time() {
+        unsafe { sys::timer_resume_game_time() }
+    }
+
+    /// Pauses the game time until the guard returned gets dropped, which
+    /// resumes it, even when returning early.
+    pub fn pause_game_time_scoped() -> GameTimePause {
+        pause_game_time();
+        GameTimePause(())
+    }
+
+    /// Resumes the game time when dropped. Created by
+    /// [`pause_game_time_scoped`].
+    #[must_use = "the game time resumes as soon as the guard is dropped"]
+    pub struct GameTimePause(());
+
+    impl Drop for GameTimePause {
+        fn drop(&mut self) {
+            resume_game_time();
+        }
+    }
+
+    pub fn set_variable(key: &str, value: &str) {
+        unsafe { sys::timer_set_variable(key.as_ptr(), key.len(), value.as_ptr(), value.len()) }
+    }
+
+    /// Sets a variable to a formatted value without allocating. The value is
+    /// formatted into a buffer on the stack and truncated to
+    /// [`MAX_FORMAT_LEN`](super::MAX_FORMAT_LEN) bytes.
+    pub fn set_variable_fmt(key: &str, value: fmt::Arguments<'_>) {
+        let mut buf = FormatBuf::new();
+        let _ = fmt::write(&mut buf, value);
+        set_variable(key, buf.as_str());
+    }
+
+    /// Sets a variable to any value that can be displayed, such as the current
+    /// health of the player. Like [`set_variable_fmt`], the value is truncated
+    /// to [`MAX_FORMAT_LEN`](super::MAX_FORMAT_LEN) bytes.
+    pub fn set_variable_display(key: &str, value: &impl fmt::Display) {
+        set_variable_fmt(key, format_args!("{value}"));
+    }
+
+    pub fn state() -> TimerState {
+        unsafe {
+            match sys::timer_get_state() {
+                sys::TimerState::NOT_RUNNING => TimerState::NotRunning,
+                sys::TimerState::PAUSED => TimerState::Paused,
+                sys::TimerState::RUNNING => TimerState::Running,
+                sys::TimerState::ENDED => TimerState::Ended,
+                _ => core::hint::unreachable_unchecked(),
+            }
+        }
+    }
+
+    /// Converts a tick count at the frequency given into a duration. Integer
+    /// frequencies are converted with exact integer math, while fractional
+    /// ones go through `f64`. Frequencies that aren't positive result in a
+    /// duration of zero, while durations too long to be represented saturate
+    /// at `i64::MAX` seconds.
+    pub fn ticks_to_duration(ticks: u64, ticks_per_second: f64) -> time::Duration {
+        if ticks_per_second.is_nan() || ticks_per_second <= 0.0 {
+            return time::Duration::ZERO;
+        }
+        let integer_rate = ticks_per_second as u64;
+        if integer_rate as f64 == ticks_per_second {
+            let secs = ticks / integer_rate;
+            let nanos = (ticks % integer_rate) as u128 * 1_000_000_000 / integer_rate as u128;
+            time::Duration::new(i64::try_from(secs).unwrap_or(i64::MAX), nanos as i32)
+        } else {
+            let secs = ticks as f64 / ticks_per_second;
+            if secs < i64::MAX as f64 {
+                time::Duration::seconds_f64(secs)
+            } else {
+                time::Duration::seconds(i64::MAX)
+            }
+        }
+    }
+
+    /// Gets the index of the split the timer is currently on, or `None` if the
+    /// timer is not running. After the final split, this is one past the index
+    /// of the last split until the timer gets reset.
+    pub fn current_split_index() -> Option<u32> {
+        let index = unsafe { sys::timer_get_current_split_index() };
+        u32::try_from(index).ok()
+    }
+
+    /// Gets the amount of attempts that have been started, including the
+    /// current one. This is `0` if the timer has never been started. A change
+    /// in this value means that a new attempt started, even if the auto
+    /// splitter didn't start it.
+    pub fn run_count() -> u32 {
+        unsafe { sys::timer_get_attempt_count() }
+    }
+
+    pub fn set_game_time(time: time::Duration) {
+        unsafe {
+            sys::timer_set_game_time(time.whole_seconds(), time.subsec_nanoseconds());
+        }
+    }
+
+    /// Gets the current game time, or `None` if the timer is not running.
+    pub fn current_game_time() -> Option<time::Duration> {
+        let (mut secs, mut nanos) = (0, 0);
+        if unsafe { sys::timer_get_game_time(&mut secs, &mut nanos) } {
+            Some(time::Duration::new(secs, nanos))
+        } else {
+            None
+        }
+    }
+
+    /// Sets the game time and then pauses it, so the runtime doesn't advance
+    /// the game time on its own past the value set.
+    pub fn set_game_time_paused(time: time::Duration) {
+        set_game_time(time);
+        pause_game_time();
+    }
+
+    /// Sets the game time directly from whole seconds and nanoseconds without
+    /// any normalization, so negative values are passed through as is.
+    pub fn set_game_time_raw(secs: i64, nanos: i32) {
+        unsafe { sys::timer_set_game_time(secs, nanos) }
+    }
+
+    /// Sets the game time from an amount of seconds. The fractional part is
+    /// rounded to the nearest nanosecond. Negative, infinite and NaN values are
+    /// clamped to zero.
+    pub fn set_game_time_seconds(seconds: f64) {
+        let seconds = if seconds.is_finite() && seconds > 0.0 {
+            seconds
+        } else {
+            0.0
+        };
+        let mut secs = seconds as i64;
+        let mut nanos = ((seconds % 1.0) * 1_000_000_000.0 + 0.5) as i32;
+        if nanos >= 1_000_000_000 {
+            secs = secs.saturating_add(1);
+            nanos -= 1_000_000_000;
+        }
+        unsafe { sys::timer_set_game_time(secs, nanos) }
+    }
+}
+
+pub mod settings {
+    use super::sys;
+
+    /// Registers a boolean setting that the user can toggle. This may be called
+    /// every tick, as registering the same key again has no effect.
+    pub fn add_bool(key: &str, description: &str, default: bool) {
+        unsafe {
+            sys::user_settings_add_bool(
+                key.as_ptr(),
+                key.len(),
+                description.as_ptr(),
+                description.len(),
+                default,
+            )
+        }
+    }
+
+    pub fn get_bool(key: &str) -> bool {
+        unsafe { sys::user_settings_get_bool(key.as_ptr(), key.len()) }
+    }
+}
+
+pub mod log {
+    use super::FormatBuf;
+    use core::{
+        fmt::{self, Write},
+        sync::atomic::{self, AtomicU8},
+    };
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[repr(u8)]
+    pub enum Level {
+        Debug,
+        Info,
+        Warn,
+        Error,
+    }
+
+    impl Level {
+        const fn tag(self) -> &'static str {
+            match self {
+                Level::Debug => "DEBUG",
+                Level::Info => "INFO",
+                Level::Warn => "WARN",
+                Level::Error => "ERROR",
+            }
+        }
+    }
+
+    static MIN_LEVEL: AtomicU8 = AtomicU8::new(Level::Debug as u8);
+
+    /// Suppresses all messages below the level given. This is filtered locally,
+    /// so suppressed messages never reach the runtime.
+    pub fn set_min_level(level: Level) {
+        MIN_LEVEL.store(level as u8, atomic::Ordering::Relaxed);
+    }
+
+    pub fn log(level: Level, args: fmt::Arguments<'_>) {
+        if (level as u8) < MIN_LEVEL.load(atomic::Ordering::Relaxed) {
+            return;
+        }
+        let mut buf = FormatBuf::new();
+        let _ = write!(buf, "[{}] {}", level.tag(), args);
+        super::print_message(buf.as_str());
+    }
+
+    pub fn debug(args: fmt::Arguments<'_>) {
+        log(Level::Debug, args)
+    }
+
+    pub fn info(args: fmt::Arguments<'_>) {
+        log(Level::Info, args)
+    }
+
+    pub fn warn(args: fmt::Arguments<'_>) {
+        log(Level::Warn, args)
+    }
+
+    pub fn error(args: fmt::Arguments<'_>) {
+        log(Level::Error, args)
+    }
+}
+
+/// The tick rate the runtime uses until a different one is set.
+pub const DEFAULT_TICK_RATE: f64 = 120.0;
+
+static TICK_RATE: AtomicU64 = AtomicU64::new(DEFAULT_TICK_RATE.to_bits());
+
+pub fn set_tick_rate(ticks_per_second: f64) {
+    TICK_RATE.store(ticks_per_second.to_bits(), atomic::Ordering::Relaxed);
+    unsafe { sys::runtime_set_tick_rate(ticks_per_second) }
+}
+
+/// The shortest interval accepted by [`set_tick_interval`].
+pub const MIN_TICK_INTERVAL: time::Duration = time::Duration::milliseconds(1);
+
+/// Sets the tick rate from the interval between two ticks. Intervals shorter
+/// than [`MIN_TICK_INTERVAL`], including zero and negative ones, are clamped to
+/// it.
+pub fn set_tick_interval(interval: time::Duration) {
+    let interval = if interval < MIN_TICK_INTERVAL {
+        MIN_TICK_INTERVAL
+    } else {
+        interval
+    };
+    set_tick_rate(1.0 / interval.as_seconds_f64());
+}
+
+/// Gets the tick rate the runtime applied, which may differ from the one set if
+/// the runtime clamped it. If the runtime can't report it, the tick rate last
+/// set is returned instead.
+pub fn get_tick_rate() -> f64 {
+    let ticks_per_second = unsafe { sys::runtime_get_tick_rate() };
+    if ticks_per_second > 0.0 {
+        ticks_per_second
+    } else {
+        f64::from_bits(TICK_RATE.load(atomic::Ordering::Relaxed))
+    }
+}
+
+/// Gets the real time that passed since an unspecified point in time, which
+/// stays the same for as long as the auto splitter runs. The difference of two
+/// calls is the time that actually elapsed between them, independent of the
+/// tick rate. Returns `None` if the runtime has no clock, in which case ticks
+/// are the only source of time.
+pub fn now() -> Option<time::Duration> {
+    let (mut secs, mut nanos) = (0, 0);
+    if unsafe { sys::runtime_get_time(&mut secs, &mut nanos) } {
+        Some(time::Duration::new(secs, nanos))
+    } else {
+        None
+    }
+}
+
+/// Blocks until `ticks` more ticks are due. The time spent waiting counts as the
+/// current tick, so values read afterwards are as fresh as they'd be on a later
+/// call to `update`.
+pub fn yield_ticks(ticks: u32) {
+    for _ in 0..ticks {
+        unsafe { sys::runtime_wait_for_next_tick() }
+    }
+}
+
+pub fn print_message(text: &str) {
+    unsafe { sys::runtime_print_message(text.as_ptr(), text.len()) }
+}
+
+/// Tells the runtime what the auto splitter is currently doing, such as
+/// "Waiting for the game" or "Offsets not found", so it can be shown to the
+/// user instead of the auto splitter silently failing.
+pub fn set_status(status: &str) {
+    unsafe { sys::runtime_set_status(status.as_ptr(), status.len()) }
+}
+
+/// Prints a formatted log message without allocating. The message is formatted
+/// into a buffer on the stack and truncated to [`MAX_FORMAT_LEN`] bytes.
+pub fn print_message_fmt(args: fmt::Arguments<'_>) {
+    let mut buf = FormatBuf::new();
+    let _ = fmt::write(&mut buf, args);
+    print_message(buf.as_str());
+}
+
+/// Prints a formatted log message without allocating. The message is truncated
+/// to [`MAX_FORMAT_LEN`] bytes.
+#[macro_export]
+macro_rules! print_fmt {
+    ($($arg:tt)*) => {
+        $crate::print_message_fmt(format_args!($($arg)*))
+    };
+}
+
+/// The maximum length in bytes of messages formatted on the stack. Anything
+/// longer gets truncated.
+pub const MAX_FORMAT_LEN: usize = 1024;
+
+struct FormatBuf {
+    buf: [u8; MAX_FORMAT_LEN],
+    len: usize,
+}
+
+impl FormatBuf {
+    const fn new() -> Self {
+        Self {
+            buf: [0; MAX_FORMAT_LEN],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
+
+impl fmt::Write for FormatBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut len = s.len().min(MAX_FORMAT_LEN - self.len);
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+        self.buf[self.len..self.len + len].copy_from_slice(&s.as_bytes()[..len]);
+        self.len += len;
+        if len == s.len() {
+            Ok(())
+        } else {
+            Err(fmt::Error)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub fn list_processes() -> Vec<String> {
+    enumerate_processes()
+        .into_iter()
+        .map(|(_, name)| name)
+        .collect()
+}
+
+#[cfg(feature = "alloc")]
+fn enumerate_processes() -> Vec<(u64, String)> {
+    let buf =
+        fill_host_buf(|buf_ptr, buf_len| unsafe { sys::runtime_list_processes(buf_ptr, buf_len) });
+    buf.map(|buf| {
+        String::from_utf8_lossy(&buf)
+            .lines()
+            .filter_map(|line| {
+                let (pid, name) = line.split_once(' ')?;
+                Some((pid.parse().ok()?, String::from(name)))
+            })
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// A snapshot of the running processes. Enumerating the processes is
+/// expensive, so it only happens when [`refresh`](Self::refresh) is called.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default)]
+pub struct ProcessList {
+    processes: Vec<(u64, String)>,
+}
+
+#[cfg(feature = "alloc")]
+impl ProcessList {
+    pub const fn new() -> Self {
+        Self {
+            processes: Vec::new(),
+        }
+    }
+
+    pub fn refresh(&mut self) {
+        self.processes = enumerate_processes();
+    }
+
+    /// Finds the process id of the first process with the name given.
+    pub fn find(&self, name: &str) -> Option<u64> {
+        self.processes
+            .iter()
+            .find(|(_, process_name)| process_name == name)
+            .map(|&(pid, _)| pid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn address_arithmetic_wraps_at_the_boundary() {
+        assert_eq!(Address(u64::MAX).checked_add(1), None);
+        assert_eq!(Address(u64::MAX) + 1u64, Address(0));
+        assert_eq!(Address(0) - 1u32, Address(u64::MAX));
+        assert_eq!(Address(0) - Address(1), u64::MAX);
+    }
+
+    #[test]
+    fn negative_offsets_near_zero() {
+        assert_eq!(Address(5).offset(-6), Address(u64::MAX));
+        assert_eq!(Address(5).checked_offset(-6), None);
+        assert_eq!(Address(5).checked_offset(-5), Some(Address(0)));
+        assert_eq!(Address(5).checked_offset(i64::MIN), None);
+    }
+
+    #[test]
+    fn ticks_to_duration_saturates() {
+        let max = time::Duration::seconds(i64::MAX);
+        assert_eq!(timer::ticks_to_duration(u64::MAX, 0.5), max);
+        assert_eq!(timer::ticks_to_duration(1000, 1e-300), max);
+    }
+
+    #[test]
+    fn big_endian_arrays_keep_their_element_order() {
+        let mut values: [u32; 2] = bytemuck::cast([0, 0, 0, 1, 0, 0, 0, 2u8]);
+        slice_to_native::<BigEndian, u32>(&mut values);
+        assert_eq!(values, [1, 2]);
+    }
 }
@@ -0,0 +1,158 @@
+use crate::{Address, Error, Process};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    bytes: Vec<u8>,
+    mask: Vec<u8>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SignatureError {
+    /// The pattern doesn't contain any bytes.
+    Empty,
+    /// The token starting at the byte index given has an odd amount of
+    /// characters.
+    OddLength { index: usize },
+    /// The token starting at the byte index given is neither a hex byte nor a
+    /// wildcard.
+    InvalidToken { index: usize },
+    /// The amount of bytes and mask entries differ.
+    LengthMismatch,
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("the signature is empty"),
+            Self::OddLength { index } => {
+                write!(f, "the token at index {index} has an odd length")
+            }
+            Self::InvalidToken { index } => {
+                write!(
+                    f,
+                    "the token at index {index} is not a hex byte or wildcard"
+                )
+            }
+            Self::LengthMismatch => f.write_str("the bytes and the mask differ in length"),
+        }
+    }
+}
+
+impl Signature {
+    /// Parses a pattern like `"48 8B ?? 89"`. Every byte is either written as
+    /// two hex digits or as `??` for a wildcard. A lone `?` is accepted as a
+    /// wildcard too. Bytes may be separated by whitespace, but don't need to
+    /// be.
+    pub fn new(pattern: &str) -> Result<Self, SignatureError> {
+        let mut bytes = Vec::new();
+        let mut mask = Vec::new();
+
+        for (index, token) in tokens(pattern) {
+            if token == "?" {
+                bytes.push(0);
+                mask.push(0);
+                continue;
+            }
+            if token.len() % 2 != 0 {
+                return Err(SignatureError::OddLength { index });
+            }
+            for pair in token.as_bytes().chunks_exact(2) {
+                match pair {
+                    b"??" => {
+                        bytes.push(0);
+                        mask.push(0);
+                    }
+                    &[high, low] => match (hex_digit(high), hex_digit(low)) {
+                        (Some(high), Some(low)) => {
+                            bytes.push(high << 4 | low);
+                            mask.push(0xFF);
+                        }
+                        _ => return Err(SignatureError::InvalidToken { index }),
+                    },
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        if bytes.is_empty() {
+            return Err(SignatureError::Empty);
+        }
+
+        Ok(Self { bytes, mask })
+    }
+
+    /// Builds a signature from raw bytes. Every byte whose entry in the mask is
+    /// `false` is a wildcard.
+    pub fn from_bytes(bytes: &[u8], mask: &[bool]) -> Result<Self, SignatureError> {
+        if bytes.len() != mask.len() {
+            return Err(SignatureError::LengthMismatch);
+        }
+        if bytes.is_empty() {
+            return Err(SignatureError::Empty);
+        }
+        let mask: Vec<u8> = mask.iter().map(|&set| if set { 0xFF } else { 0 }).collect();
+        let bytes = bytes.iter().zip(&mask).map(|(b, m)| b & m).collect();
+        Ok(Self { bytes, mask })
+    }
+
+    /// The bytes to match. Bytes that are wildcards are zero.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The mask of the signature. Each byte is `0xFF` if the byte needs to
+    /// match and `0x00` if it is a wildcard.
+    pub fn mask(&self) -> &[u8] {
+        &self.mask
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+/// Remembers the results of [`Process::scan_signature`] by pattern, so
+/// scanning for the same pattern again doesn't scan the process again. Only
+/// successful scans are remembered. The cache needs to be invalidated whenever
+/// the process changes, as its results would be stale otherwise.
+#[derive(Debug, Clone, Default)]
+pub struct SignatureCache {
+    results: BTreeMap<String, Address>,
+}
+
+impl SignatureCache {
+    pub const fn new() -> Self {
+        Self {
+            results: BTreeMap::new(),
+        }
+    }
+
+    pub fn scan(&mut self, process: &Process, signature: &str) -> Result<Address, Error> {
+        if let Some(&address) = self.results.get(signature) {
+            return Ok(address);
+        }
+        let address = process.scan_signature(signature)?;
+        self.results.insert(String::from(signature), address);
+        Ok(address)
+    }
+
+    pub fn invalidate(&mut self) {
+        self.results.clear();
+    }
+}
+
+fn tokens(pattern: &str) -> impl Iterator<Item = (usize, &str)> {
+    pattern
+        .split_ascii_whitespace()
+        .map(move |token| (token.as_ptr() as usize - pattern.as_ptr() as usize, token))
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+    (c as char).to_digit(16).map(|d| d as u8)
+}
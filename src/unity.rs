@@ -0,0 +1,46 @@
+use crate::{Address, Error, Process};
+use alloc::string::String;
+
+/// How the characters of a string are encoded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// The length is the amount of bytes, followed by UTF-8 bytes.
+    Utf8,
+    /// The length is the amount of UTF-16 code units, followed by
+    /// little-endian UTF-16 code units. This is how Mono and IL2CPP store
+    /// `System.String`.
+    Utf16,
+}
+
+/// Reads a length-prefixed string behind a pointer path. The path needs to
+/// resolve to the `u32` length that directly precedes the characters. For a
+/// `System.String` in a 64-bit Mono or IL2CPP game, that is offset `0x10` into
+/// the string object. Lengths larger than `max_len` are clamped to it, which
+/// protects against garbage lengths.
+pub fn read_string(
+    process: &Process,
+    base: u64,
+    path: &[u64],
+    encoding: Encoding,
+    max_len: usize,
+) -> Result<String, Error> {
+    let address = process.resolve_pointer_path64(base, path)?;
+    match encoding {
+        Encoding::Utf8 => process.read_len_prefixed_string(address, max_len),
+        Encoding::Utf16 => {
+            let len: u32 = process.read(address)?;
+            process.read_utf16_str(address + 4u64, (len as usize).min(max_len))
+        }
+    }
+}
+
+/// Like [`read_string`], but starts at an [`Address`].
+pub fn read_string_at(
+    process: &Process,
+    base: Address,
+    path: &[u64],
+    encoding: Encoding,
+    max_len: usize,
+) -> Result<String, Error> {
+    read_string(process, base.0, path, encoding, max_len)
+}
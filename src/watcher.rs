@@ -1,3 +1,5 @@
+use crate::{Address, Error, Process};
+use bytemuck::Pod;
 use core::{mem, ops};
 
 #[derive(Copy, Clone, Default)]
@@ -9,6 +11,17 @@ impl<T> Watcher<T> {
     pub const fn new() -> Self {
         Self { pair: None }
     }
+
+    /// Gets the old and current value, or `None` until the first value got
+    /// stored. The first value stored becomes both the old and the current
+    /// value, so it never registers as a change.
+    pub fn pair(&self) -> Option<&Pair<T>> {
+        self.pair.as_ref()
+    }
+
+    pub fn current_opt(&self) -> Option<&T> {
+        self.pair.as_ref().map(|pair| &pair.current)
+    }
 }
 
 impl<T: Copy> Watcher<T> {
@@ -29,6 +42,42 @@ impl<T: Copy> Watcher<T> {
         }
         self.pair.as_ref()
     }
+
+    pub fn update_infallible(&mut self, value: T) -> &Pair<T> {
+        let pair = self.pair.get_or_insert(Pair {
+            old: value,
+            current: value,
+        });
+        pair.old = mem::replace(&mut pair.current, value);
+        pair
+    }
+
+    pub fn update_from_path(
+        &mut self,
+        process: &Process,
+        base: u64,
+        path: &[u64],
+    ) -> Result<&Pair<T>, Error>
+    where
+        T: Pod,
+    {
+        let value = process.read_pointer_path64(base, path)?;
+        Ok(self.update_infallible(value))
+    }
+
+    /// Like [`update_from_path`](Self::update_from_path), but starts at an
+    /// [`Address`].
+    pub fn update_from_path_at(
+        &mut self,
+        process: &Process,
+        base: Address,
+        path: &[u64],
+    ) -> Result<&Pair<T>, Error>
+    where
+        T: Pod,
+    {
+        self.update_from_path(process, base.0, path)
+    }
 }
 
 #[derive(Copy, Clone, Default)]
@@ -50,3 +99,92 @@ impl<T> Pair<T> {
         !f(&self.old) && f(&self.current)
     }
 }
+
+impl<T: PartialEq> Pair<T> {
+    pub fn changed(&self) -> bool {
+        self.old != self.current
+    }
+
+    pub fn changed_to(&self, value: &T) -> bool {
+        self.old != *value && self.current == *value
+    }
+}
+
+impl<T: PartialOrd> Pair<T> {
+    pub fn increased(&self) -> bool {
+        self.current > self.old
+    }
+
+    pub fn decreased(&self) -> bool {
+        self.current < self.old
+    }
+
+    /// Checks whether the value just dropped below the threshold.
+    pub fn crossed_below(&self, threshold: T) -> bool {
+        self.check(|value| *value < threshold)
+    }
+
+    /// Checks whether the value just rose above the threshold.
+    pub fn crossed_above(&self, threshold: T) -> bool {
+        self.check(|value| *value > threshold)
+    }
+}
+
+/// Declares a struct of [`Watcher`]s, each of which gets its value from a
+/// pointer path relative to a module. The generated `update` method reads and
+/// updates every field. Fields whose read fails are left untouched, and the
+/// first error encountered is returned once all the fields were handled.
+///
+/// ```ignore
+/// asr::state! {
+///     struct State {
+///         health: u32 = "game.exe", [0x1234, 0x10],
+///         level: u8 = "game.exe", [0x5678, 0x20, 0x8],
+///     }
+/// }
+///
+/// let mut state = State::new();
+/// state.update(&process)?;
+/// ```
+#[macro_export]
+macro_rules! state {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($field_vis:vis $field:ident: $ty:ty = $module:expr, [$($offset:expr),* $(,)?]),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $($field_vis $field: $crate::watcher::Watcher<$ty>,)*
+        }
+
+        impl $name {
+            $vis const fn new() -> Self {
+                Self {
+                    $($field: $crate::watcher::Watcher::new(),)*
+                }
+            }
+
+            $vis fn update(
+                &mut self,
+                process: &$crate::Process,
+            ) -> ::core::result::Result<(), $crate::Error> {
+                let mut result = Ok(());
+                $(
+                    match process.read_pointer_path_from_module::<$ty>($module, &[$($offset),*]) {
+                        Ok(value) => {
+                            self.$field.update_infallible(value);
+                        }
+                        Err(error) => {
+                            if result.is_ok() {
+                                result = Err(error);
+                            }
+                        }
+                    }
+                )*
+                result
+            }
+        }
+    };
+}